@@ -48,8 +48,8 @@ fn get_mono_audio_data<'a>(planes: &'a Planes) -> &'a [f32] {
 
 fn get_params(language: &str) -> FullParams {
     // create a params object
-    // note that currently the only implemented strategy is Greedy, BeamSearch is a WIP
-    // n_past defaults to 0
+    // n_past defaults to 0; switch to SamplingStrategy::BeamSearch for more
+    // accurate (but slower) decoding on noisy audio
     let mut params = FullParams::new(SamplingStrategy::Greedy { n_past: 0 });
     // and set the language to translate to
     params.set_language(language);
@@ -79,8 +79,11 @@ fn transcribe(input: &str, model_path: &str, language: &str) -> Result<()> {
     let mut decoder = AudioDecoder::from_stream(stream)?.build()?;
 
     // load a context and model
-    let mut ctx = WhisperContext::new(model_path)
+    let ctx = WhisperContext::new(model_path)
         .map_err(|e| anyhow!("failed loading whisper model: {:?}", e))?;
+    let mut state = ctx
+        .create_state()
+        .map_err(|e| anyhow!("failed creating whisper state: {:?}", e))?;
 
     // audio data needs to have the following format:
     // - f32
@@ -134,14 +137,17 @@ fn transcribe(input: &str, model_path: &str, language: &str) -> Result<()> {
         full_data.extend(data);
     }
 
-    ctx.full(get_params(language), &full_data[..])
+    state
+        .full(get_params(language), &full_data[..])
         .map_err(|e| anyhow!("failed loading whisper model: {:?}", e))?;
     // fetch the results
-    let num_segments = ctx.full_n_segments();
+    let num_segments = state.full_n_segments();
     for i in 0..num_segments {
-        let segment = ctx.full_get_segment_text(i).expect("failed to get segment");
-        let start_timestamp = ctx.full_get_segment_t0(i);
-        let end_timestamp = ctx.full_get_segment_t1(i);
+        let segment = state
+            .full_get_segment_text(i)
+            .expect("failed to get segment");
+        let start_timestamp = state.full_get_segment_t0(i);
+        let end_timestamp = state.full_get_segment_t1(i);
         println!("[{} - {}]: {}", start_timestamp, end_timestamp, segment);
     }
 
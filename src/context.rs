@@ -0,0 +1,61 @@
+use std::ffi::CString;
+
+use crate::{WhisperError, WhisperState};
+
+/// A loaded whisper.cpp model.
+///
+/// `WhisperContext` only owns the (large, read-only) model weights; it holds
+/// no decode state of its own. To actually transcribe anything, call
+/// [`create_state`](Self::create_state) to obtain a [`WhisperState`], which
+/// can be driven independently of any other state created from the same
+/// context. This lets a single loaded model back several concurrent
+/// transcriptions, each with its own KV cache and results.
+pub struct WhisperContext {
+    ctx: *mut whisper_rs_sys::whisper_context,
+}
+
+// The underlying `whisper_context` is read-only once loaded; all mutable
+// decode state lives in `WhisperState` instead, so sharing a context across
+// threads is safe.
+unsafe impl Send for WhisperContext {}
+unsafe impl Sync for WhisperContext {}
+
+impl WhisperContext {
+    /// Load a whisper.cpp model from `path` (a `ggml`-format `.bin` file).
+    pub fn new(path: &str) -> Result<Self, WhisperError> {
+        let path = CString::new(path)?;
+        // SAFETY: `path` is a valid, NUL-terminated C string for the duration
+        // of this call.
+        let ctx = unsafe { whisper_rs_sys::whisper_init_from_file(path.as_ptr()) };
+        if ctx.is_null() {
+            return Err(WhisperError::InitError);
+        }
+        Ok(Self { ctx })
+    }
+
+    /// Create a new, independent decode state against this model.
+    ///
+    /// Each `WhisperState` has its own KV cache and result segments, so
+    /// multiple states created from the same `WhisperContext` can transcribe
+    /// concurrently on different threads.
+    pub fn create_state(&self) -> Result<WhisperState<'_>, WhisperError> {
+        // SAFETY: `self.ctx` is a valid, initialized context.
+        let state = unsafe { whisper_rs_sys::whisper_init_state(self.ctx) };
+        if state.is_null() {
+            return Err(WhisperError::InitError);
+        }
+        Ok(WhisperState::new(self, state))
+    }
+
+    pub(crate) fn ctx_ptr(&self) -> *mut whisper_rs_sys::whisper_context {
+        self.ctx
+    }
+}
+
+impl Drop for WhisperContext {
+    fn drop(&mut self) {
+        // SAFETY: `self.ctx` was created by `whisper_init_from_file` and is
+        // only ever freed here.
+        unsafe { whisper_rs_sys::whisper_free(self.ctx) };
+    }
+}
@@ -0,0 +1,47 @@
+use std::ffi::NulError;
+use std::fmt;
+
+/// Errors that can be raised by this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhisperError {
+    /// `whisper_init_from_file` returned a null context, usually because the
+    /// model file does not exist or is not a valid `ggml` model, or
+    /// `whisper_init_state` returned a null state.
+    InitError,
+    /// A Rust string contained an interior NUL byte and could not be passed to
+    /// whisper.cpp as a C string.
+    InvalidString,
+    /// `whisper_full_with_state`, `whisper_pcm_to_mel_with_state` or
+    /// `whisper_set_mel_with_state` returned a non-zero error code.
+    FullTranscribeError(i32),
+    /// A segment index was out of range for the current result set.
+    InvalidSegmentIndex,
+    /// A token index was out of range for the current segment.
+    InvalidTokenIndex,
+    /// Writing rendered output (SRT/VTT/plain text) to the target `Write`
+    /// failed, as opposed to an error reading the underlying segment data.
+    WriteError(std::io::ErrorKind),
+}
+
+impl fmt::Display for WhisperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WhisperError::InitError => write!(f, "failed to initialize whisper context"),
+            WhisperError::InvalidString => write!(f, "string contained an interior NUL byte"),
+            WhisperError::FullTranscribeError(code) => {
+                write!(f, "whisper.cpp returned non-zero error code {}", code)
+            }
+            WhisperError::InvalidSegmentIndex => write!(f, "segment index out of range"),
+            WhisperError::InvalidTokenIndex => write!(f, "token index out of range"),
+            WhisperError::WriteError(kind) => write!(f, "failed to write output: {}", kind),
+        }
+    }
+}
+
+impl std::error::Error for WhisperError {}
+
+impl From<NulError> for WhisperError {
+    fn from(_: NulError) -> Self {
+        WhisperError::InvalidString
+    }
+}
@@ -0,0 +1,168 @@
+use std::ffi::CString;
+use std::os::raw::c_int;
+
+/// Strategy whisper.cpp uses to pick the next token while decoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SamplingStrategy {
+    /// Always pick the single most likely next token.
+    Greedy {
+        /// Forwarded to whisper.cpp's `greedy.best_of`: the number of
+        /// candidate decodes greedy sampling considers before picking the
+        /// best one. This does *not* carry decode context across separate
+        /// `full()` calls; `0` uses whisper.cpp's default.
+        n_past: c_int,
+    },
+    /// Keep `beam_size` candidate sequences alive at every step and settle on
+    /// the best-scoring one. `patience` is a factor, not a step count.
+    ///
+    /// Beam search trades throughput for accuracy: it explores more of the
+    /// decoding tree than greedy decoding, which makes it considerably less
+    /// prone to hallucinating on noisy audio.
+    BeamSearch {
+        /// Number of beams to keep around.
+        beam_size: c_int,
+        /// Patience factor passed straight through to whisper.cpp's
+        /// `beam_search.patience`. `-1.0` disables the patience heuristic.
+        patience: f32,
+    },
+}
+
+/// Parameters controlling a single [`full`](crate::WhisperState::full) run.
+pub struct FullParams {
+    pub(crate) fp: whisper_rs_sys::whisper_full_params,
+    language: Option<CString>,
+}
+
+impl FullParams {
+    /// Create a new set of parameters using the supplied sampling `strategy`.
+    pub fn new(strategy: SamplingStrategy) -> FullParams {
+        let sampling_strategy = match strategy {
+            SamplingStrategy::Greedy { .. } => {
+                whisper_rs_sys::whisper_sampling_strategy_WHISPER_SAMPLING_GREEDY
+            }
+            SamplingStrategy::BeamSearch { .. } => {
+                whisper_rs_sys::whisper_sampling_strategy_WHISPER_SAMPLING_BEAM_SEARCH
+            }
+        };
+
+        // SAFETY: `whisper_full_default_params` just fills in a plain-old-data
+        // struct with whisper.cpp's defaults for the given strategy.
+        let mut fp = unsafe { whisper_rs_sys::whisper_full_default_params(sampling_strategy) };
+
+        match strategy {
+            SamplingStrategy::Greedy { n_past } => {
+                fp.greedy.best_of = n_past;
+            }
+            SamplingStrategy::BeamSearch {
+                beam_size,
+                patience,
+            } => {
+                fp.beam_search.beam_size = beam_size;
+                fp.beam_search.patience = patience;
+            }
+        }
+
+        Self { fp, language: None }
+    }
+
+    /// Set the language whisper.cpp should transcribe/translate to (e.g. `"en"`).
+    /// Pass `"auto"` to let whisper.cpp detect the language itself.
+    pub fn set_language(&mut self, language: &str) {
+        let language = CString::new(language).expect("language contains a NUL byte");
+        self.fp.language = language.as_ptr();
+        self.language = Some(language);
+    }
+
+    /// Whether to print special tokens (e.g. `<|endoftext|>`) to stdout.
+    pub fn set_print_special(&mut self, print_special: bool) {
+        self.fp.print_special = print_special;
+    }
+
+    /// Whether whisper.cpp should print progress information to stdout.
+    pub fn set_print_progress(&mut self, print_progress: bool) {
+        self.fp.print_progress = print_progress;
+    }
+
+    /// Whether whisper.cpp should print results as they are produced.
+    pub fn set_print_realtime(&mut self, print_realtime: bool) {
+        self.fp.print_realtime = print_realtime;
+    }
+
+    /// Whether to print per-segment timestamps to stdout.
+    pub fn set_print_timestamps(&mut self, print_timestamps: bool) {
+        self.fp.print_timestamps = print_timestamps;
+    }
+
+    /// Whether to translate the audio into English instead of transcribing it
+    /// in its source language.
+    pub fn set_translate(&mut self, translate: bool) {
+        self.fp.translate = translate;
+    }
+
+    /// Start decoding `offset_ms` milliseconds into the PCM buffer passed to
+    /// [`full`](crate::WhisperState::full), instead of at the start.
+    ///
+    /// Segment timestamps are still reported relative to the start of the
+    /// buffer, so chunking a long recording by repeatedly slicing `full_data`
+    /// would lose the absolute timeline; setting the offset here keeps it
+    /// intact.
+    pub fn set_offset_ms(&mut self, offset_ms: c_int) {
+        self.fp.offset_ms = offset_ms;
+    }
+
+    /// Only decode `duration_ms` milliseconds of audio starting from
+    /// `offset_ms`. `0` (the default) means "until the end of the buffer".
+    pub fn set_duration_ms(&mut self, duration_ms: c_int) {
+        self.fp.duration_ms = duration_ms;
+    }
+
+    /// Whether to compute per-token timestamps in addition to per-segment
+    /// ones. Required for
+    /// [`WhisperState::full_get_token_data`](crate::WhisperState::full_get_token_data)
+    /// to return meaningful `t0`/`t1` values. Off by default, since it costs
+    /// extra compute that most callers reading whole segments don't need.
+    ///
+    /// Note this does not by itself populate
+    /// [`TokenData::t_dtw`](crate::TokenData::t_dtw); that requires
+    /// whisper.cpp's separate DTW token-level-timestamp alignment to be
+    /// enabled on the context/model.
+    pub fn set_token_timestamps(&mut self, token_timestamps: bool) {
+        self.fp.token_timestamps = token_timestamps;
+    }
+}
+
+// `language` keeps the backing `CString` for `fp.language` alive for the
+// lifetime of `FullParams`; `fp` itself has no independent ownership of it.
+unsafe impl Send for FullParams {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These only exercise the Rust -> whisper_full_params mapping, so unlike
+    // the WhisperState integration tests they don't need a ggml model file.
+
+    #[test]
+    fn greedy_sets_best_of() {
+        let params = FullParams::new(SamplingStrategy::Greedy { n_past: 3 });
+        assert_eq!(
+            params.fp.strategy,
+            whisper_rs_sys::whisper_sampling_strategy_WHISPER_SAMPLING_GREEDY
+        );
+        assert_eq!(params.fp.greedy.best_of, 3);
+    }
+
+    #[test]
+    fn beam_search_sets_beam_size_and_patience() {
+        let params = FullParams::new(SamplingStrategy::BeamSearch {
+            beam_size: 5,
+            patience: 0.2,
+        });
+        assert_eq!(
+            params.fp.strategy,
+            whisper_rs_sys::whisper_sampling_strategy_WHISPER_SAMPLING_BEAM_SEARCH
+        );
+        assert_eq!(params.fp.beam_search.beam_size, 5);
+        assert_eq!(params.fp.beam_search.patience, 0.2);
+    }
+}
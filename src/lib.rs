@@ -0,0 +1,16 @@
+//! Safe Rust bindings to [whisper.cpp](https://github.com/ggerganov/whisper.cpp).
+
+mod context;
+mod error;
+mod full_params;
+mod output;
+mod state;
+mod stream;
+mod token;
+
+pub use context::WhisperContext;
+pub use error::WhisperError;
+pub use full_params::{FullParams, SamplingStrategy};
+pub use state::WhisperState;
+pub use stream::{StreamConfig, StreamSegment, StreamingTranscriber, Vad, VadConfig, VadEvent};
+pub use token::TokenData;
@@ -0,0 +1,124 @@
+use std::io::{self, Write};
+
+use crate::{WhisperError, WhisperState};
+
+/// Map the `io::Error` a `write_*` call can fail with back to a
+/// [`WhisperError`]. The only `io::Error` these functions ever produce
+/// themselves is an `InvalidData` wrapping a segment-lookup failure (see
+/// [`WhisperState::full_get_segment_text`]); anything else is a genuine
+/// failure to write to the caller's `Write` (e.g. a full disk).
+fn io_err_to_whisper_error(err: io::Error) -> WhisperError {
+    if err.kind() == io::ErrorKind::InvalidData {
+        WhisperError::InvalidSegmentIndex
+    } else {
+        WhisperError::WriteError(err.kind())
+    }
+}
+
+/// Format a whisper.cpp timestamp (in centiseconds) as `HH:MM:SS<sep>mmm`.
+fn format_timestamp(centiseconds: i64, fractional_separator: char) -> String {
+    let total_ms = centiseconds * 10;
+    let hours = total_ms / 3_600_000;
+    let minutes = (total_ms % 3_600_000) / 60_000;
+    let seconds = (total_ms % 60_000) / 1_000;
+    let millis = total_ms % 1_000;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        hours, minutes, seconds, fractional_separator, millis
+    )
+}
+
+impl<'a> WhisperState<'a> {
+    /// Render the segments from the last [`full`](Self::full) call as an SRT
+    /// subtitle file.
+    pub fn segments_to_srt(&self) -> Result<String, WhisperError> {
+        let mut out = Vec::new();
+        self.write_srt(&mut out).map_err(io_err_to_whisper_error)?;
+        Ok(String::from_utf8_lossy(&out).into_owned())
+    }
+
+    /// Render the segments from the last [`full`](Self::full) call as a
+    /// WebVTT subtitle file.
+    pub fn segments_to_vtt(&self) -> Result<String, WhisperError> {
+        let mut out = Vec::new();
+        self.write_vtt(&mut out).map_err(io_err_to_whisper_error)?;
+        Ok(String::from_utf8_lossy(&out).into_owned())
+    }
+
+    /// Render the segments from the last [`full`](Self::full) call as plain
+    /// text, one segment per line.
+    pub fn segments_to_txt(&self) -> Result<String, WhisperError> {
+        let mut out = Vec::new();
+        self.write_txt(&mut out).map_err(io_err_to_whisper_error)?;
+        Ok(String::from_utf8_lossy(&out).into_owned())
+    }
+
+    /// Write the segments from the last [`full`](Self::full) call to `writer`
+    /// as an SRT subtitle file.
+    pub fn write_srt<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for i in 0..self.full_n_segments() {
+            let text = self
+                .full_get_segment_text(i)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            writeln!(writer, "{}", i + 1)?;
+            writeln!(
+                writer,
+                "{} --> {}",
+                format_timestamp(self.full_get_segment_t0(i), ','),
+                format_timestamp(self.full_get_segment_t1(i), ',')
+            )?;
+            writeln!(writer, "{}", text.trim())?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Write the segments from the last [`full`](Self::full) call to `writer`
+    /// as a WebVTT subtitle file.
+    pub fn write_vtt<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "WEBVTT")?;
+        writeln!(writer)?;
+        for i in 0..self.full_n_segments() {
+            let text = self
+                .full_get_segment_text(i)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            writeln!(
+                writer,
+                "{} --> {}",
+                format_timestamp(self.full_get_segment_t0(i), '.'),
+                format_timestamp(self.full_get_segment_t1(i), '.')
+            )?;
+            writeln!(writer, "{}", text.trim())?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Write the segments from the last [`full`](Self::full) call to `writer`
+    /// as plain text, one segment per line.
+    pub fn write_txt<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for i in 0..self.full_n_segments() {
+            let text = self
+                .full_get_segment_text(i)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            writeln!(writer, "{}", text.trim())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_timestamp;
+
+    #[test]
+    fn formats_srt_timestamp() {
+        // 1 min 2.34s, expressed in centiseconds.
+        assert_eq!(format_timestamp(6_234, ','), "00:01:02,340");
+    }
+
+    #[test]
+    fn formats_vtt_timestamp() {
+        assert_eq!(format_timestamp(0, '.'), "00:00:00.000");
+    }
+}
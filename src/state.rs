@@ -0,0 +1,329 @@
+use std::ffi::CStr;
+use std::os::raw::c_int;
+
+use crate::{FullParams, TokenData, WhisperContext, WhisperError};
+
+/// Independent decode state (KV cache + results) for transcribing against a
+/// shared [`WhisperContext`].
+///
+/// Obtain one via [`WhisperContext::create_state`]. A `WhisperState` borrows
+/// its context, so several of them (typically one per request) can be
+/// created from a context shared behind an `Arc` and driven concurrently on
+/// separate threads.
+pub struct WhisperState<'a> {
+    ctx: &'a WhisperContext,
+    state: *mut whisper_rs_sys::whisper_state,
+}
+
+// `state` is owned by this `WhisperState` alone and is independent of any
+// other `WhisperState` created from the same context. The type is `Send` but
+// not `Sync`, so it is never accessed from two threads at once.
+unsafe impl<'a> Send for WhisperState<'a> {}
+
+impl<'a> WhisperState<'a> {
+    pub(crate) fn new(ctx: &'a WhisperContext, state: *mut whisper_rs_sys::whisper_state) -> Self {
+        Self { ctx, state }
+    }
+
+    /// Run a full transcription pass over `data` (mono, 16 kHz, `f32` PCM
+    /// samples in `[-1.0, 1.0]`), using the given `params`.
+    pub fn full(&mut self, params: FullParams, data: &[f32]) -> Result<(), WhisperError> {
+        // SAFETY: `self.ctx` and `self.state` are both valid for the lifetime
+        // of `self`, and `data` is a valid slice of `f32` samples of length
+        // `data.len()`.
+        let ret = unsafe {
+            whisper_rs_sys::whisper_full_with_state(
+                self.ctx.ctx_ptr(),
+                self.state,
+                params.fp,
+                data.as_ptr(),
+                data.len() as c_int,
+            )
+        };
+        if ret != 0 {
+            return Err(WhisperError::FullTranscribeError(ret));
+        }
+        Ok(())
+    }
+
+    /// Compute and store the 80-bin log-mel spectrogram for `samples` (mono,
+    /// 16 kHz `f32` PCM), using `n_threads` threads.
+    ///
+    /// This is the first stage [`full`](Self::full) normally runs internally.
+    /// Calling it directly lets the expensive mel computation be cached and
+    /// reused across multiple `full()` calls with different [`FullParams`]
+    /// (e.g. re-decoding the same audio in a different language).
+    pub fn pcm_to_mel(&mut self, samples: &[f32], n_threads: i32) -> Result<(), WhisperError> {
+        // SAFETY: `self.ctx` and `self.state` are valid, and `samples` is a
+        // valid slice of `f32` samples of length `samples.len()`.
+        let ret = unsafe {
+            whisper_rs_sys::whisper_pcm_to_mel_with_state(
+                self.ctx.ctx_ptr(),
+                self.state,
+                samples.as_ptr(),
+                samples.len() as c_int,
+                n_threads,
+            )
+        };
+        if ret != 0 {
+            return Err(WhisperError::FullTranscribeError(ret));
+        }
+        Ok(())
+    }
+
+    /// Inject a precomputed log-mel spectrogram directly, bypassing
+    /// [`pcm_to_mel`](Self::pcm_to_mel). `data` holds `n_mel` mel bins per
+    /// frame, laid out frame-major, as produced by an external mel pipeline
+    /// (e.g. a `realfft`-based spectrogram).
+    pub fn set_mel(&mut self, data: &[f32], n_mel: i32) -> Result<(), WhisperError> {
+        // `n_len` is a *frame* count; `data.len()` is `n_len * n_mel` since
+        // `data` is laid out frame-major with `n_mel` bins per frame.
+        let n_len = data.len() as c_int / n_mel;
+        // SAFETY: `self.ctx` and `self.state` are valid, and `data` is a
+        // valid slice of `f32` of length `n_len * n_mel == data.len()`.
+        let ret = unsafe {
+            whisper_rs_sys::whisper_set_mel_with_state(
+                self.ctx.ctx_ptr(),
+                self.state,
+                data.as_ptr(),
+                n_len,
+                n_mel,
+            )
+        };
+        if ret != 0 {
+            return Err(WhisperError::FullTranscribeError(ret));
+        }
+        Ok(())
+    }
+
+    /// Number of segments produced by the last [`full`](Self::full) call.
+    pub fn full_n_segments(&self) -> i32 {
+        unsafe { whisper_rs_sys::whisper_full_n_segments_from_state(self.state) }
+    }
+
+    /// Start time of `segment`, in centiseconds.
+    pub fn full_get_segment_t0(&self, segment: i32) -> i64 {
+        unsafe { whisper_rs_sys::whisper_full_get_segment_t0_from_state(self.state, segment) }
+    }
+
+    /// End time of `segment`, in centiseconds.
+    pub fn full_get_segment_t1(&self, segment: i32) -> i64 {
+        unsafe { whisper_rs_sys::whisper_full_get_segment_t1_from_state(self.state, segment) }
+    }
+
+    /// Text of `segment`.
+    pub fn full_get_segment_text(&self, segment: i32) -> Result<String, WhisperError> {
+        if segment < 0 || segment >= self.full_n_segments() {
+            return Err(WhisperError::InvalidSegmentIndex);
+        }
+        // SAFETY: `segment` was just bounds-checked against `full_n_segments`.
+        let c_str = unsafe {
+            let ptr = whisper_rs_sys::whisper_full_get_segment_text_from_state(self.state, segment);
+            CStr::from_ptr(ptr)
+        };
+        Ok(c_str.to_string_lossy().into_owned())
+    }
+
+    /// Number of tokens in `segment`. Meaningful per-token `t0`/`t1` values
+    /// additionally need
+    /// [`FullParams::set_token_timestamps`](crate::FullParams::set_token_timestamps)
+    /// to have been enabled for the `full()` call that produced it.
+    pub fn full_n_tokens(&self, segment: i32) -> i32 {
+        unsafe { whisper_rs_sys::whisper_full_n_tokens_from_state(self.state, segment) }
+    }
+
+    /// Text of `token` within `segment`, including special tokens such as
+    /// timestamp markers.
+    pub fn full_get_token_text(&self, segment: i32, token: i32) -> Result<String, WhisperError> {
+        if token < 0 || token >= self.full_n_tokens(segment) {
+            return Err(WhisperError::InvalidTokenIndex);
+        }
+        // SAFETY: `token` was just bounds-checked against `full_n_tokens`, and
+        // `self.ctx` owns the vocabulary the token text is looked up in.
+        let c_str = unsafe {
+            let ptr = whisper_rs_sys::whisper_full_get_token_text_from_state(
+                self.ctx.ctx_ptr(),
+                self.state,
+                segment,
+                token,
+            );
+            CStr::from_ptr(ptr)
+        };
+        Ok(c_str.to_string_lossy().into_owned())
+    }
+
+    /// Vocabulary id of `token` within `segment`.
+    pub fn full_get_token_id(&self, segment: i32, token: i32) -> i32 {
+        unsafe { whisper_rs_sys::whisper_full_get_token_id_from_state(self.state, segment, token) }
+    }
+
+    /// Probability whisper.cpp assigned to `token` within `segment`. Useful
+    /// for filtering out low-confidence, likely-hallucinated tokens.
+    pub fn full_get_token_p(&self, segment: i32, token: i32) -> f32 {
+        unsafe { whisper_rs_sys::whisper_full_get_token_p_from_state(self.state, segment, token) }
+    }
+
+    /// Full per-token metadata for `token` within `segment`. `t0`/`t1` are
+    /// whisper.cpp's plain estimated per-token timestamps; see
+    /// [`TokenData::t_dtw`] for the separately-computed DTW-aligned word
+    /// timestamp.
+    pub fn full_get_token_data(&self, segment: i32, token: i32) -> TokenData {
+        let raw = unsafe {
+            whisper_rs_sys::whisper_full_get_token_data_from_state(self.state, segment, token)
+        };
+        TokenData::from(raw)
+    }
+}
+
+impl<'a> Drop for WhisperState<'a> {
+    fn drop(&mut self) {
+        // SAFETY: `self.state` was created by `whisper_init_state` and is
+        // only ever freed here.
+        unsafe { whisper_rs_sys::whisper_free_state(self.state) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SamplingStrategy;
+
+    /// Path to a small `ggml` model used by the integration tests, supplied
+    /// out-of-band since model weights are too large to check in.
+    fn test_model_path() -> Option<String> {
+        std::env::var("WHISPER_RS_TEST_MODEL").ok()
+    }
+
+    fn test_pcm() -> Vec<f32> {
+        // A couple of seconds of silence-ish low-level noise is enough to
+        // exercise the decode path without needing a real audio fixture.
+        (0..16_000 * 2)
+            .map(|i| ((i as f32) * 0.001).sin() * 0.01)
+            .collect()
+    }
+
+    fn run_strategy(strategy: SamplingStrategy) {
+        let Some(model_path) = test_model_path() else {
+            eprintln!("skipping: WHISPER_RS_TEST_MODEL not set");
+            return;
+        };
+
+        let ctx = WhisperContext::new(&model_path).expect("failed to load model");
+        let mut state = ctx.create_state().expect("failed to create state");
+        let mut params = FullParams::new(strategy);
+        params.set_print_special(false);
+        params.set_print_progress(false);
+        params.set_print_realtime(false);
+        params.set_print_timestamps(false);
+
+        state.full(params, &test_pcm()).expect("full() failed");
+
+        assert!(state.full_n_segments() > 0, "expected at least one segment");
+    }
+
+    #[test]
+    #[ignore = "requires WHISPER_RS_TEST_MODEL to point at a local ggml model"]
+    fn greedy_produces_segments() {
+        run_strategy(SamplingStrategy::Greedy { n_past: 0 });
+    }
+
+    #[test]
+    #[ignore = "requires WHISPER_RS_TEST_MODEL to point at a local ggml model"]
+    fn beam_search_produces_segments() {
+        run_strategy(SamplingStrategy::BeamSearch {
+            beam_size: 5,
+            patience: -1.0,
+        });
+    }
+
+    #[test]
+    #[ignore = "requires WHISPER_RS_TEST_MODEL to point at a local ggml model"]
+    fn two_states_share_one_context() {
+        let Some(model_path) = test_model_path() else {
+            eprintln!("skipping: WHISPER_RS_TEST_MODEL not set");
+            return;
+        };
+
+        let ctx = WhisperContext::new(&model_path).expect("failed to load model");
+        let mut state_a = ctx.create_state().expect("failed to create state");
+        let mut state_b = ctx.create_state().expect("failed to create state");
+
+        let pcm = test_pcm();
+        state_a
+            .full(
+                FullParams::new(SamplingStrategy::Greedy { n_past: 0 }),
+                &pcm,
+            )
+            .expect("full() failed for state_a");
+        state_b
+            .full(
+                FullParams::new(SamplingStrategy::Greedy { n_past: 0 }),
+                &pcm,
+            )
+            .expect("full() failed for state_b");
+
+        assert!(state_a.full_n_segments() > 0);
+        assert!(state_b.full_n_segments() > 0);
+    }
+
+    #[test]
+    #[ignore = "requires WHISPER_RS_TEST_MODEL to point at a local ggml model"]
+    fn cached_mel_can_be_decoded_with_different_params() {
+        let Some(model_path) = test_model_path() else {
+            eprintln!("skipping: WHISPER_RS_TEST_MODEL not set");
+            return;
+        };
+
+        let ctx = WhisperContext::new(&model_path).expect("failed to load model");
+        let mut state = ctx.create_state().expect("failed to create state");
+        state
+            .pcm_to_mel(&test_pcm(), 1)
+            .expect("pcm_to_mel() failed");
+
+        let mut params = FullParams::new(SamplingStrategy::Greedy { n_past: 0 });
+        params.set_language("en");
+        state
+            .full(params, &[])
+            .expect("full() over cached mel failed");
+
+        assert!(state.full_n_segments() > 0);
+
+        // Decode the same cached mel again, this time with different params.
+        let mut params = FullParams::new(SamplingStrategy::Greedy { n_past: 0 });
+        params.set_language("de");
+        state
+            .full(params, &[])
+            .expect("second full() over cached mel failed");
+
+        assert!(state.full_n_segments() > 0);
+    }
+
+    #[test]
+    #[ignore = "requires WHISPER_RS_TEST_MODEL to point at a local ggml model"]
+    fn token_timestamps_expose_per_token_data() {
+        let Some(model_path) = test_model_path() else {
+            eprintln!("skipping: WHISPER_RS_TEST_MODEL not set");
+            return;
+        };
+
+        let ctx = WhisperContext::new(&model_path).expect("failed to load model");
+        let mut state = ctx.create_state().expect("failed to create state");
+        let mut params = FullParams::new(SamplingStrategy::Greedy { n_past: 0 });
+        params.set_token_timestamps(true);
+
+        state.full(params, &test_pcm()).expect("full() failed");
+
+        assert!(state.full_n_segments() > 0);
+        let n_tokens = state.full_n_tokens(0);
+        assert!(n_tokens > 0, "expected at least one token in segment 0");
+
+        for t in 0..n_tokens {
+            let data = state.full_get_token_data(0, t);
+            assert_eq!(data.id, state.full_get_token_id(0, t));
+            assert!((0.0..=1.0).contains(&state.full_get_token_p(0, t)));
+            state
+                .full_get_token_text(0, t)
+                .expect("failed to get token text");
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use std::collections::VecDeque;
+
+use crate::{FullParams, WhisperError, WhisperState};
+
+/// A single finalized transcript segment emitted by [`StreamingTranscriber`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamSegment {
+    /// Start time within the current window, in centiseconds.
+    pub t0: i64,
+    /// End time within the current window, in centiseconds.
+    pub t1: i64,
+    pub text: String,
+}
+
+/// Tunables for the voice-activity gate.
+#[derive(Debug, Clone, Copy)]
+pub struct VadConfig {
+    /// Frame size used for energy analysis, in milliseconds.
+    pub frame_ms: u32,
+    /// Minimum duration of continuous voiced audio before it is considered
+    /// real speech rather than a transient.
+    pub min_voiced_ms: u32,
+    /// How long a trailing silence has to last before a window is flushed.
+    pub trailing_silence_ms: u32,
+    /// Margin, in linear RMS units, added on top of the adaptive noise floor
+    /// before a frame is classified as voiced.
+    pub energy_margin: f32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            frame_ms: 30,
+            min_voiced_ms: 150,
+            trailing_silence_ms: 500,
+            energy_margin: 0.006,
+        }
+    }
+}
+
+/// Simple energy-based voice-activity detector with an adaptive noise floor.
+///
+/// Each ~30 ms frame's RMS energy is compared against an exponential moving
+/// average of the energy seen during silence. Callers who already have a
+/// spectral front-end (e.g. a `realfft`-based one) can additionally weigh in
+/// a `spectral_energy_ratio` (high-frequency / low-frequency band energy) via
+/// [`Vad::process_frame_with_ratio`] to reject low-frequency rumble that pure
+/// RMS gating would otherwise treat as speech.
+pub struct Vad {
+    config: VadConfig,
+    frame_len: usize,
+    noise_floor: f32,
+    voiced_ms: u32,
+    silent_ms: u32,
+    boundary_reported: bool,
+}
+
+/// Result of feeding one frame through [`Vad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VadEvent {
+    /// The frame was not classified as speech.
+    Silence,
+    /// The frame is speech, but hasn't yet been voiced for `min_voiced_ms`.
+    Transient,
+    /// The frame is speech and the minimum voiced duration has been met.
+    Voiced,
+    /// The frame is silence and the trailing-silence gap has elapsed; the
+    /// caller should flush the current window.
+    Boundary,
+}
+
+impl Vad {
+    pub fn new(sample_rate: u32, config: VadConfig) -> Self {
+        let frame_len = (sample_rate as u64 * config.frame_ms as u64 / 1000) as usize;
+        Self {
+            config,
+            frame_len: frame_len.max(1),
+            noise_floor: 0.0,
+            voiced_ms: 0,
+            silent_ms: 0,
+            boundary_reported: false,
+        }
+    }
+
+    /// Number of samples expected per frame at this VAD's sample rate.
+    pub fn frame_len(&self) -> usize {
+        self.frame_len
+    }
+
+    fn rms(frame: &[f32]) -> f32 {
+        if frame.is_empty() {
+            return 0.0;
+        }
+        let sum_sq: f32 = frame.iter().map(|s| s * s).sum();
+        (sum_sq / frame.len() as f32).sqrt()
+    }
+
+    /// Classify a single `frame_ms`-long frame of `f32` samples in
+    /// `[-1.0, 1.0]`.
+    pub fn process_frame(&mut self, frame: &[f32]) -> VadEvent {
+        self.process_frame_with_ratio(frame, None)
+    }
+
+    /// Like [`process_frame`](Self::process_frame), but additionally takes a
+    /// caller-supplied spectral high/low energy ratio (e.g. from a real FFT)
+    /// that must exceed `1.0` for the frame to be eligible as speech.
+    pub fn process_frame_with_ratio(
+        &mut self,
+        frame: &[f32],
+        spectral_energy_ratio: Option<f32>,
+    ) -> VadEvent {
+        let energy = Self::rms(frame);
+        let threshold = self.noise_floor + self.config.energy_margin;
+        let passes_spectral = spectral_energy_ratio.is_none_or(|ratio| ratio > 1.0);
+        let is_speech = energy > threshold && passes_spectral;
+
+        if is_speech {
+            self.silent_ms = 0;
+            self.boundary_reported = false;
+            self.voiced_ms = self.voiced_ms.saturating_add(self.config.frame_ms);
+            if self.voiced_ms >= self.config.min_voiced_ms {
+                VadEvent::Voiced
+            } else {
+                VadEvent::Transient
+            }
+        } else {
+            // Only adapt the noise floor while we're confidently in silence,
+            // so a loud speaker doesn't raise the threshold against itself.
+            if self.voiced_ms == 0 {
+                self.noise_floor = self.noise_floor * 0.95 + energy * 0.05;
+            }
+            self.voiced_ms = 0;
+            self.silent_ms = self.silent_ms.saturating_add(self.config.frame_ms);
+            if self.silent_ms >= self.config.trailing_silence_ms {
+                // Only report the boundary once per silence run, on the
+                // Silence -> Boundary transition; otherwise every subsequent
+                // silent frame would re-trigger a flush of an (almost)
+                // unchanged buffer for as long as the silence lasts.
+                if self.boundary_reported {
+                    VadEvent::Silence
+                } else {
+                    self.boundary_reported = true;
+                    VadEvent::Boundary
+                }
+            } else {
+                VadEvent::Silence
+            }
+        }
+    }
+}
+
+/// Tunables for [`StreamingTranscriber`]'s sliding decode window.
+#[derive(Debug, Clone, Copy)]
+pub struct StreamConfig {
+    /// Sample rate of the incoming PCM, in Hz. whisper.cpp expects 16000.
+    pub sample_rate: u32,
+    /// Length of the sliding decode window, in seconds.
+    pub window_secs: f32,
+    /// How much audio to carry over from the end of one window into the
+    /// start of the next, in seconds, so words aren't cut mid-word at a
+    /// window boundary.
+    pub overlap_secs: f32,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: 16_000,
+            window_secs: 20.0,
+            overlap_secs: 2.0,
+        }
+    }
+}
+
+/// Real-time-friendly front-end for [`WhisperState`].
+///
+/// Feed it small PCM chunks as they arrive (e.g. from a `cpal` capture
+/// callback); it buffers them in a ring, gates decoding behind [`Vad`], and
+/// runs `full()` over a sliding window once enough trailing silence has been
+/// seen, carrying [`StreamConfig::overlap_secs`] of audio into the next
+/// window so words aren't lost at the boundary.
+pub struct StreamingTranscriber<'a> {
+    state: WhisperState<'a>,
+    vad: Vad,
+    config: StreamConfig,
+    ring: VecDeque<f32>,
+    frame: Vec<f32>,
+}
+
+impl<'a> StreamingTranscriber<'a> {
+    pub fn new(state: WhisperState<'a>, config: StreamConfig, vad_config: VadConfig) -> Self {
+        let vad = Vad::new(config.sample_rate, vad_config);
+        Self {
+            state,
+            frame: Vec::with_capacity(vad.frame_len()),
+            vad,
+            config,
+            ring: VecDeque::new(),
+        }
+    }
+
+    fn window_len(&self) -> usize {
+        (self.config.sample_rate as f32 * self.config.window_secs) as usize
+    }
+
+    fn overlap_len(&self) -> usize {
+        (self.config.sample_rate as f32 * self.config.overlap_secs) as usize
+    }
+
+    /// Feed newly captured samples in. Returns any segments finalized by a
+    /// window flush triggered by this call (empty most of the time).
+    ///
+    /// `make_params` is called once per flushed window so callers can supply
+    /// fresh [`FullParams`] for each decode (e.g. a different language or
+    /// initial prompt). Continuity across window boundaries comes from the
+    /// `overlap_secs` of audio carried into the next window, not from any
+    /// decode-side state.
+    pub fn push_samples(
+        &mut self,
+        samples: &[f32],
+        make_params: impl Fn() -> FullParams,
+    ) -> Result<Vec<StreamSegment>, WhisperError> {
+        let mut flushed = Vec::new();
+
+        for &sample in samples {
+            self.frame.push(sample);
+            self.ring.push_back(sample);
+            if self.ring.len() > self.window_len() {
+                self.ring.pop_front();
+            }
+
+            // Force a flush once the window is full, regardless of VAD
+            // state, so continuous speech with no pause long enough to hit
+            // `trailing_silence_ms` (a lecture, podcast, or narration - the
+            // exact case this sliding window exists for) still gets decoded
+            // instead of having its oldest samples silently evicted by the
+            // ring above. `flush` shrinks the ring back down to
+            // `overlap_len()`, so this only fires once per time the window
+            // actually fills, not on every subsequent sample.
+            let window_full = self.ring.len() >= self.window_len();
+
+            if self.frame.len() < self.vad.frame_len() {
+                if window_full {
+                    flushed.extend(self.flush(&make_params)?);
+                }
+                continue;
+            }
+            let event = self.vad.process_frame(&self.frame);
+            self.frame.clear();
+
+            let vad_flush = event == VadEvent::Boundary && self.ring.len() >= self.overlap_len();
+            if window_full || vad_flush {
+                flushed.extend(self.flush(&make_params)?);
+            }
+        }
+
+        Ok(flushed)
+    }
+
+    /// Force a decode over whatever is currently buffered, regardless of the
+    /// VAD state. Useful when the caller knows the stream has ended.
+    pub fn flush(
+        &mut self,
+        make_params: &impl Fn() -> FullParams,
+    ) -> Result<Vec<StreamSegment>, WhisperError> {
+        if self.ring.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let window: Vec<f32> = self.ring.iter().copied().collect();
+        self.state.full(make_params(), &window)?;
+
+        let mut segments = Vec::with_capacity(self.state.full_n_segments() as usize);
+        for i in 0..self.state.full_n_segments() {
+            segments.push(StreamSegment {
+                t0: self.state.full_get_segment_t0(i),
+                t1: self.state.full_get_segment_t1(i),
+                text: self.state.full_get_segment_text(i)?,
+            });
+        }
+
+        // Keep the overlap tail so the next window's decode has context
+        // across the boundary.
+        let overlap_len = self.overlap_len().min(self.ring.len());
+        while self.ring.len() > overlap_len {
+            self.ring.pop_front();
+        }
+
+        Ok(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn silent_frame(len: usize) -> Vec<f32> {
+        vec![0.0; len]
+    }
+
+    fn loud_frame(len: usize) -> Vec<f32> {
+        (0..len).map(|i| ((i as f32) * 0.3).sin() * 0.5).collect()
+    }
+
+    #[test]
+    fn classifies_silence_as_silence() {
+        let mut vad = Vad::new(16_000, VadConfig::default());
+        let frame = silent_frame(vad.frame_len());
+        assert_eq!(vad.process_frame(&frame), VadEvent::Silence);
+    }
+
+    #[test]
+    fn requires_min_voiced_duration_before_reporting_voiced() {
+        let config = VadConfig {
+            min_voiced_ms: 90,
+            ..VadConfig::default()
+        };
+        let mut vad = Vad::new(16_000, config);
+        let frame = loud_frame(vad.frame_len());
+
+        assert_eq!(vad.process_frame(&frame), VadEvent::Transient);
+        assert_eq!(vad.process_frame(&frame), VadEvent::Transient);
+        assert_eq!(vad.process_frame(&frame), VadEvent::Voiced);
+    }
+
+    #[test]
+    fn reports_boundary_after_trailing_silence_gap() {
+        let config = VadConfig {
+            trailing_silence_ms: 60,
+            ..VadConfig::default()
+        };
+        let mut vad = Vad::new(16_000, config);
+        let silence = silent_frame(vad.frame_len());
+
+        assert_eq!(vad.process_frame(&silence), VadEvent::Silence);
+        assert_eq!(vad.process_frame(&silence), VadEvent::Boundary);
+    }
+
+    #[test]
+    fn boundary_fires_only_once_per_silence_run() {
+        let config = VadConfig {
+            trailing_silence_ms: 60,
+            ..VadConfig::default()
+        };
+        let mut vad = Vad::new(16_000, config);
+        let silence = silent_frame(vad.frame_len());
+        let voiced = loud_frame(vad.frame_len());
+
+        assert_eq!(vad.process_frame(&silence), VadEvent::Silence);
+        assert_eq!(vad.process_frame(&silence), VadEvent::Boundary);
+        // Further silence must not keep re-reporting a boundary every frame.
+        for _ in 0..5 {
+            assert_eq!(vad.process_frame(&silence), VadEvent::Silence);
+        }
+
+        // Once speech resumes and then stops again, a new boundary should
+        // fire for the new silence run.
+        let _ = vad.process_frame(&voiced);
+        let _ = vad.process_frame(&voiced);
+        assert_eq!(vad.process_frame(&silence), VadEvent::Silence);
+        assert_eq!(vad.process_frame(&silence), VadEvent::Boundary);
+    }
+
+    #[test]
+    fn spectral_ratio_can_veto_a_loud_low_frequency_frame() {
+        let mut vad = Vad::new(16_000, VadConfig::default());
+        let frame = loud_frame(vad.frame_len());
+        assert_eq!(
+            vad.process_frame_with_ratio(&frame, Some(0.5)),
+            VadEvent::Silence
+        );
+    }
+
+    /// Path to a small `ggml` model used by the integration test below,
+    /// supplied out-of-band since model weights are too large to check in.
+    fn test_model_path() -> Option<String> {
+        std::env::var("WHISPER_RS_TEST_MODEL").ok()
+    }
+
+    #[test]
+    #[ignore = "requires WHISPER_RS_TEST_MODEL to point at a local ggml model"]
+    fn streaming_transcriber_flushes_a_window_on_trailing_silence() {
+        use crate::{SamplingStrategy, WhisperContext};
+
+        let Some(model_path) = test_model_path() else {
+            eprintln!("skipping: WHISPER_RS_TEST_MODEL not set");
+            return;
+        };
+
+        let ctx = WhisperContext::new(&model_path).expect("failed to load model");
+        let state = ctx.create_state().expect("failed to create state");
+        let stream_config = StreamConfig {
+            window_secs: 2.0,
+            overlap_secs: 0.2,
+            ..StreamConfig::default()
+        };
+        let vad_config = VadConfig {
+            trailing_silence_ms: 90,
+            ..VadConfig::default()
+        };
+        let mut transcriber = StreamingTranscriber::new(state, stream_config, vad_config);
+
+        let make_params = || {
+            let mut params = FullParams::new(SamplingStrategy::Greedy { n_past: 0 });
+            params.set_print_special(false);
+            params.set_print_progress(false);
+            params.set_print_realtime(false);
+            params.set_print_timestamps(false);
+            params
+        };
+
+        // A second of low-level "speech-ish" noise followed by enough
+        // silence to clear `trailing_silence_ms` should trigger exactly one
+        // flush.
+        let voiced: Vec<f32> = (0..16_000)
+            .map(|i| ((i as f32) * 0.05).sin() * 0.1)
+            .collect();
+        let silence = vec![0.0f32; 16_000 / 2];
+
+        let mut flushed = transcriber
+            .push_samples(&voiced, make_params)
+            .expect("push_samples failed on voiced audio");
+        flushed.extend(
+            transcriber
+                .push_samples(&silence, make_params)
+                .expect("push_samples failed on silence"),
+        );
+
+        assert!(
+            !flushed.is_empty(),
+            "expected trailing silence to flush at least one window"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+/// Per-token decode metadata, as produced by whisper.cpp when
+/// [`FullParams::set_token_timestamps`](crate::FullParams::set_token_timestamps)
+/// is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenData {
+    /// Vocabulary id of the token.
+    pub id: i32,
+    /// Probability of the token.
+    pub p: f32,
+    /// Log-probability of the token.
+    pub plog: f32,
+    /// Estimated start time of the token, in centiseconds.
+    pub t0: i64,
+    /// Estimated end time of the token, in centiseconds.
+    pub t1: i64,
+    /// DTW-aligned word timestamp, in centiseconds, when whisper.cpp's DTW
+    /// token-level-timestamp alignment is enabled; `-1` otherwise.
+    pub t_dtw: i64,
+    /// Estimated voiced length of the token.
+    pub vlen: f32,
+}
+
+impl From<whisper_rs_sys::whisper_token_data> for TokenData {
+    fn from(raw: whisper_rs_sys::whisper_token_data) -> Self {
+        Self {
+            id: raw.id,
+            p: raw.p,
+            plog: raw.plog,
+            t0: raw.t0,
+            t1: raw.t1,
+            t_dtw: raw.t_dtw,
+            vlen: raw.vlen,
+        }
+    }
+}